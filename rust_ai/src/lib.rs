@@ -34,26 +34,38 @@
 //!      where q1, q2, q3 are quadrances (squared distances)
 //! ```
 
-use num_traits::Num;
+use num_rational::Ratio;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num};
 use std::ops::{Add, Mul, Sub};
 
 /// A numeric type that can be used in rational trigonometry calculations.
-/// Supports integers, rational numbers (fractions), and floating-point numbers.
+/// Supports integers, rational numbers (fractions), and floating-point numbers,
+/// as well as arbitrary-precision types such as `num_rational::BigRational`.
+///
+/// The bound is based on [`Clone`] plus `num_traits::{Zero, One}` (via [`Num`])
+/// rather than `Copy` plus `From<i32>`, so heap-backed big integers — which are
+/// only `Clone`, not `Copy` — qualify and give unbounded-precision arithmetic.
 pub trait Numeric:
-    Num + Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + From<i32>
+    Num + Clone + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
 {
 }
 impl<T> Numeric for T where
-    T: Num
-        + Copy
-        + PartialOrd
-        + Add<Output = Self>
-        + Sub<Output = Self>
-        + Mul<Output = Self>
-        + From<i32>
+    T: Num + Clone + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
 {
 }
 
+/// Builds the value `n` of a numeric type by repeated addition of `one`.
+/// Used in place of `From<i32>` so the formulas stay generic over types such
+/// as `BigRational` that do not implement `From<i32>`.
+fn from_u32<T: Numeric>(n: u32) -> T {
+    let one = T::one();
+    let mut acc = T::zero();
+    for _ in 0..n {
+        acc = acc + one.clone();
+    }
+    acc
+}
+
 /// A 2D vector with numeric components
 pub type Vector2<T> = [T; 2];
 
@@ -95,8 +107,8 @@ pub type Vector2<T> = [T; 2];
 ///             q2
 /// ```
 pub fn archimedes<T: Numeric>(q_1: T, q_2: T, q_3: T) -> T {
-    let temp = q_1 + q_2 - q_3;
-    T::from(4) * q_1 * q_2 - temp * temp
+    let temp = q_1.clone() + q_2.clone() - q_3;
+    from_u32::<T>(4) * q_1 * q_2 - temp.clone() * temp
 }
 
 /// The `cross` function calculates the cross product of two vectors `v_1` and `v_2`.
@@ -132,7 +144,7 @@ pub fn archimedes<T: Numeric>(q_1: T, q_2: T, q_3: T) -> T {
 ///           O
 /// ```
 pub fn cross<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
-    v_1[0] * v_2[1] - v_1[1] * v_2[0]
+    v_1[0].clone() * v_2[1].clone() - v_1[1].clone() * v_2[0].clone()
 }
 
 /// The `dot` function calculates the dot product of two vectors `v_1` and `v_2`.
@@ -170,7 +182,7 @@ pub fn cross<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
 ///           O         projection
 /// ```
 pub fn dot<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
-    v_1[0] * v_2[0] + v_1[1] * v_2[1]
+    v_1[0].clone() * v_2[0].clone() + v_1[1].clone() * v_2[1].clone()
 }
 
 /// The `quad` function calculates the quadrance of a vector `v`.
@@ -206,7 +218,7 @@ pub fn dot<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
 ///              O         v[0]
 /// ```
 pub fn quad<T: Numeric>(v: Vector2<T>) -> T {
-    v[0] * v[0] + v[1] * v[1]
+    v[0].clone() * v[0].clone() + v[1].clone() * v[1].clone()
 }
 
 /// The `spread` function calculates the spread between two vectors `v_1` and `v_2`.
@@ -233,10 +245,95 @@ pub fn quad<T: Numeric>(v: Vector2<T>) -> T {
 /// assert_eq!(spread(v_1, v_2), Ratio::new(4, 125));
 /// ```
 pub fn spread<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
-    let cross_product = cross(v_1, v_2);
+    let cross_product = cross(v_1.clone(), v_2.clone());
     let quad_1 = quad(v_1);
     let quad_2 = quad(v_2);
-    (cross_product * cross_product) / (quad_1 * quad_2)
+    (cross_product.clone() * cross_product) / (quad_1 * quad_2)
+}
+
+/// A symmetric bilinear form selecting one of the three chromogeometries.
+///
+/// - `Blue` is the standard Euclidean form `x1*x2 + y1*y2`.
+/// - `Red` is the relativistic form `x1*x2 - y1*y2`.
+/// - `Green` is the relativistic form `x1*y2 + x2*y1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Euclidean metric `x1*x2 + y1*y2`.
+    Blue,
+    /// Relativistic metric `x1*x2 - y1*y2`.
+    Red,
+    /// Relativistic metric `x1*y2 + x2*y1`.
+    Green,
+}
+
+/// The `dot_m` function calculates the symmetric bilinear form of two vectors
+/// `v_1` and `v_2` associated with the given [`Metric`]. For [`Metric::Blue`]
+/// this is the usual [`dot`] product.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::{dot_m, Metric};
+///
+/// assert_eq!(dot_m([1, 2], [3, 4], Metric::Blue), 11);
+/// assert_eq!(dot_m([1, 2], [3, 4], Metric::Red), -5);
+/// assert_eq!(dot_m([1, 2], [3, 4], Metric::Green), 10);
+/// ```
+pub fn dot_m<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>, metric: Metric) -> T {
+    match metric {
+        Metric::Blue => v_1[0].clone() * v_2[0].clone() + v_1[1].clone() * v_2[1].clone(),
+        Metric::Red => v_1[0].clone() * v_2[0].clone() - v_1[1].clone() * v_2[1].clone(),
+        Metric::Green => v_1[0].clone() * v_2[1].clone() + v_2[0].clone() * v_1[1].clone(),
+    }
+}
+
+/// The `quad_m` function calculates the quadrance of a vector `v` under the
+/// given [`Metric`], i.e. the form applied to `v` with itself. For
+/// [`Metric::Blue`] this is the usual [`quad`].
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::{quad_m, Metric};
+///
+/// assert_eq!(quad_m([3, 4], Metric::Blue), 25);
+/// assert_eq!(quad_m([3, 4], Metric::Red), -7);
+/// assert_eq!(quad_m([3, 4], Metric::Green), 24);
+/// ```
+pub fn quad_m<T: Numeric>(v: Vector2<T>, metric: Metric) -> T {
+    dot_m(v.clone(), v, metric)
+}
+
+/// The `spread_m` function calculates the spread between two vectors `v_1` and
+/// `v_2` under the given [`Metric`], defined as
+/// `1 - dot_m(v1, v2)^2 / (quad_m(v1) * quad_m(v2))`. For [`Metric::Blue`]
+/// this reproduces [`spread`].
+///
+/// Returns `None` when either vector is null under the metric (a zero
+/// quadrance), since the spread is then undefined.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::{spread_m, Metric};
+/// use num_rational::Ratio;
+///
+/// let v_1 = [Ratio::new(1, 1), Ratio::new(2, 1)];
+/// let v_2 = [Ratio::new(3, 1), Ratio::new(4, 1)];
+/// assert_eq!(spread_m(v_1, v_2, Metric::Blue), Some(Ratio::new(4, 125)));
+///
+/// // A null vector under the red metric has no spread.
+/// let null = [Ratio::new(1, 1), Ratio::new(1, 1)];
+/// assert_eq!(spread_m(null, v_2, Metric::Red), None);
+/// ```
+pub fn spread_m<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>, metric: Metric) -> Option<T> {
+    let quad_1 = quad_m(v_1.clone(), metric);
+    let quad_2 = quad_m(v_2.clone(), metric);
+    if quad_1 == T::zero() || quad_2 == T::zero() {
+        return None;
+    }
+    let dot_product = dot_m(v_1, v_2, metric);
+    Some(T::one() - (dot_product.clone() * dot_product) / (quad_1 * quad_2))
 }
 
 /// The `spread_law` function calculates the spread of a triangle using the law of spreads.
@@ -265,8 +362,8 @@ pub fn spread<T: Numeric>(v_1: Vector2<T>, v_2: Vector2<T>) -> T {
 /// assert_eq!(spread_law(q_1, q_2, q_3), 0.8);
 /// ```
 pub fn spread_law<T: Numeric>(q_1: T, q_2: T, q_3: T) -> T {
-    let numerator = archimedes(q_1, q_2, q_3); // 4*q_1*q_2 - (q_1 + q_2 - q_3)^2
-    let denominator = T::from(4) * q_1 * q_2;
+    let numerator = archimedes(q_1.clone(), q_2.clone(), q_3); // 4*q_1*q_2 - (q_1 + q_2 - q_3)^2
+    let denominator = from_u32::<T>(4) * q_1 * q_2;
     numerator / denominator
 }
 
@@ -297,8 +394,486 @@ pub fn spread_law<T: Numeric>(q_1: T, q_2: T, q_3: T) -> T {
 /// ```
 pub fn triple_quad_formula<T: Numeric>(q_1: T, q_2: T, s_3: T) -> T {
     // Formula: (q_1 + q_2)^2 - 4*q_1*q_2*(1-s_3)
-    let sum = q_1 + q_2;
-    sum * sum - T::from(4) * q_1 * q_2 * (T::one() - s_3)
+    let sum = q_1.clone() + q_2.clone();
+    sum.clone() * sum - from_u32::<T>(4) * q_1 * q_2 * (T::one() - s_3)
+}
+
+/// A numeric type supporting overflow-checked arithmetic, used by the
+/// `checked_*` variants of the core formulas. This is satisfied by the
+/// machine integer types (`i32`, `i64`, ...) where products of quadrances can
+/// silently overflow, and by `Ratio` over those integers; floating-point types
+/// do not implement the checked operations and so keep only the infallible API.
+pub trait CheckedNumeric: Numeric + CheckedAdd + CheckedMul + CheckedSub {}
+impl<T> CheckedNumeric for T where T: Numeric + CheckedAdd + CheckedMul + CheckedSub {}
+
+/// The `checked_archimedes` function is the overflow-safe counterpart of
+/// [`archimedes`]. It returns `None` if any intermediate product or sum
+/// overflows the integer type instead of wrapping to a wrong answer.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::checked_archimedes;
+///
+/// assert_eq!(checked_archimedes(2, 4, 6), Some(32));
+/// // The squared magnitude overflows i32.
+/// assert_eq!(checked_archimedes(i32::MAX, i32::MAX, 0), None);
+/// ```
+pub fn checked_archimedes<T: CheckedNumeric>(q_1: T, q_2: T, q_3: T) -> Option<T> {
+    let temp = q_1.checked_add(&q_2)?.checked_sub(&q_3)?;
+    let four_q1_q2 = from_u32::<T>(4).checked_mul(&q_1)?.checked_mul(&q_2)?;
+    let temp_sq = temp.checked_mul(&temp)?;
+    four_q1_q2.checked_sub(&temp_sq)
+}
+
+/// The `checked_quad` function is the overflow-safe counterpart of [`quad`].
+/// It returns `None` if squaring or summing the components overflows.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::checked_quad;
+///
+/// assert_eq!(checked_quad([3, 4]), Some(25));
+/// assert_eq!(checked_quad([i32::MAX, 0]), None);
+/// ```
+pub fn checked_quad<T: CheckedNumeric>(v: Vector2<T>) -> Option<T> {
+    let x_sq = v[0].checked_mul(&v[0])?;
+    let y_sq = v[1].checked_mul(&v[1])?;
+    x_sq.checked_add(&y_sq)
+}
+
+/// The `checked_triple_quad_formula` function is the overflow-safe counterpart
+/// of [`triple_quad_formula`]. It returns `None` on any intermediate overflow.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::checked_triple_quad_formula;
+///
+/// assert_eq!(checked_triple_quad_formula(1, 1, 1), Some(4));
+/// assert_eq!(checked_triple_quad_formula(i64::MAX, i64::MAX, 0), None);
+/// ```
+pub fn checked_triple_quad_formula<T: CheckedNumeric>(q_1: T, q_2: T, s_3: T) -> Option<T> {
+    let sum = q_1.checked_add(&q_2)?;
+    let sum_sq = sum.checked_mul(&sum)?;
+    let one_minus = T::one().checked_sub(&s_3)?;
+    let prod = from_u32::<T>(4)
+        .checked_mul(&q_1)?
+        .checked_mul(&q_2)?
+        .checked_mul(&one_minus)?;
+    sum_sq.checked_sub(&prod)
+}
+
+/// The `cross_law` function applies the rational-trigonometry cross law
+/// `(Q1 + Q2 - Q3)^2 = 4*Q1*Q2*(1 - s3)`, solving it for the spread `s3`
+/// opposite the quadrance `q_3`:
+///
+/// ```text
+/// s3 = 1 - (Q1 + Q2 - Q3)^2 / (4 * Q1 * Q2)
+/// ```
+///
+/// This is the solve-for-spread direction of the cross law; solving for a
+/// missing quadrance instead requires a square root and is provided, for the
+/// rational case, by [`Triangle::from_quadrances_and_spread`].
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::cross_law;
+/// use num_rational::Ratio;
+///
+/// // The spread opposite Q3 = 20 in the (5, 25, 20) triangle is 4/5.
+/// let s_3 = cross_law(Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1));
+/// assert_eq!(s_3, Ratio::new(4, 5));
+/// ```
+pub fn cross_law<T: Numeric>(q_1: T, q_2: T, q_3: T) -> T {
+    let temp = q_1.clone() + q_2.clone() - q_3;
+    T::one() - (temp.clone() * temp) / (from_u32::<T>(4) * q_1 * q_2)
+}
+
+/// A triangle described by its three quadrances and the three spreads
+/// opposite them, in the order `[Q1, Q2, Q3]` and `[S1, S2, S3]` where `Si` is
+/// the spread at the vertex opposite `Qi`.
+///
+/// The spreads are derived from the quadrances through the cross law, and the
+/// triple spread formula gives an exact internal consistency check.
+///
+/// ```text
+///           A
+///           |\
+///        q3 | \ q2
+///           |  \
+///           B---C
+///            q1
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle<T> {
+    /// The three quadrances `[Q1, Q2, Q3]`.
+    pub quadrances: [T; 3],
+    /// The three spreads `[S1, S2, S3]`, with `Si` opposite `Qi`.
+    pub spreads: [T; 3],
+}
+
+impl<T: Numeric> Triangle<T> {
+    /// Builds a triangle from its three quadrances, solving for the three
+    /// spreads via the cross law (see [`solve_spreads`]).
+    ///
+    /// [`solve_spreads`]: Triangle::solve_spreads
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rat_trig::Triangle;
+    /// use num_rational::Ratio;
+    ///
+    /// let t = Triangle::from_quadrances(Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1));
+    /// assert_eq!(
+    ///     t.spreads,
+    ///     [Ratio::new(1, 5), Ratio::new(1, 1), Ratio::new(4, 5)]
+    /// );
+    /// ```
+    pub fn from_quadrances(q_1: T, q_2: T, q_3: T) -> Self {
+        let mut triangle = Triangle {
+            quadrances: [q_1, q_2, q_3],
+            spreads: [T::zero(), T::zero(), T::zero()],
+        };
+        triangle.spreads = triangle.solve_spreads();
+        triangle
+    }
+
+    /// Solves for the three spreads `[S1, S2, S3]` from the stored quadrances
+    /// using the cross law, with `Si` opposite `Qi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rat_trig::Triangle;
+    /// use num_rational::Ratio;
+    ///
+    /// let t = Triangle::from_quadrances(Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1));
+    /// assert_eq!(
+    ///     t.solve_spreads(),
+    ///     [Ratio::new(1, 5), Ratio::new(1, 1), Ratio::new(4, 5)]
+    /// );
+    /// ```
+    pub fn solve_spreads(&self) -> [T; 3] {
+        let q_1 = self.quadrances[0].clone();
+        let q_2 = self.quadrances[1].clone();
+        let q_3 = self.quadrances[2].clone();
+        let s_1 = cross_law(q_2.clone(), q_3.clone(), q_1.clone());
+        let s_2 = cross_law(q_1.clone(), q_3.clone(), q_2.clone());
+        let s_3 = cross_law(q_1, q_2, q_3);
+        [s_1, s_2, s_3]
+    }
+
+    /// Checks that the three spreads satisfy the triple spread formula
+    /// `(s1 + s2 + s3)^2 = 2*(s1^2 + s2^2 + s3^2) + 4*s1*s2*s3`, the exact
+    /// condition for the spreads to come from a real triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rat_trig::Triangle;
+    /// use num_rational::Ratio;
+    ///
+    /// let t = Triangle::from_quadrances(Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1));
+    /// assert!(t.is_consistent());
+    /// ```
+    pub fn is_consistent(&self) -> bool {
+        let s_1 = self.spreads[0].clone();
+        let s_2 = self.spreads[1].clone();
+        let s_3 = self.spreads[2].clone();
+        let sum = s_1.clone() + s_2.clone() + s_3.clone();
+        let lhs = sum.clone() * sum;
+        let sum_sq =
+            s_1.clone() * s_1.clone() + s_2.clone() * s_2.clone() + s_3.clone() * s_3.clone();
+        let rhs = from_u32::<T>(2) * sum_sq + from_u32::<T>(4) * s_1 * s_2 * s_3;
+        lhs == rhs
+    }
+}
+
+impl Triangle<Ratio<i64>> {
+    /// Builds a triangle from a mix of two quadrances `q_1`, `q_2` and the
+    /// spread `s_3` between them, solving the cross law
+    /// `(Q1 + Q2 - Q3)^2 = 4*Q1*Q2*(1 - s3)` for the missing quadrance `Q3`.
+    ///
+    /// Because the cross law is quadratic in `Q3`, the solution
+    /// `Q3 = Q1 + Q2 - sqrt(4*Q1*Q2*(1 - s3))` exists as a rational only when
+    /// the discriminant is a perfect-square rational; otherwise the length
+    /// leaves exact arithmetic and `None` is returned. This is why the solver
+    /// specializes to `Ratio<i64>` rather than staying fully generic — it
+    /// relies on [`rational_length`] to take the root exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rat_trig::Triangle;
+    /// use num_rational::Ratio;
+    ///
+    /// let t = Triangle::from_quadrances_and_spread(
+    ///     Ratio::new(5, 1),
+    ///     Ratio::new(25, 1),
+    ///     Ratio::new(4, 5),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(t.quadrances, [Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1)]);
+    /// assert!(t.is_consistent());
+    /// ```
+    pub fn from_quadrances_and_spread(
+        q_1: Ratio<i64>,
+        q_2: Ratio<i64>,
+        s_3: Ratio<i64>,
+    ) -> Option<Self> {
+        let one = Ratio::new(1, 1);
+        let discriminant = Ratio::new(4, 1) * q_1 * q_2 * (one - s_3);
+        let root = rational_length(discriminant)?;
+        let q_3 = q_1 + q_2 - root;
+        Some(Self::from_quadrances(q_1, q_2, q_3))
+    }
+}
+
+/// Computes the integer floor of the square root of a non-negative 128-bit
+/// integer using Newton's method. Used internally by [`approx_sqrt`] to seed
+/// the continued-fraction surd state.
+fn isqrt_i128(n: i128) -> i128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// The `approx_sqrt` function computes the best rational convergent to the
+/// square root of a non-negative rational `r` using the continued-fraction
+/// expansion of the quadratic surd `sqrt(r)`.
+///
+/// # Precondition
+///
+/// `r` must be non-negative; a negative `r` has no real square root and, in a
+/// debug build, trips a `debug_assert!`.
+///
+/// The surd state is kept as the integer triple `(P, Q, D)` meaning
+/// `x_i = (P + sqrt(D)) / Q`, with `D = r.numer * r.denom` (so that
+/// `sqrt(r) = sqrt(D) / r.denom`). Each step takes
+/// `a = floor((P + floor(sqrt(D))) / Q)`, `P' = a*Q - P`, and
+/// `Q' = (D - P'^2) / Q`, while the convergent numerators and denominators
+/// follow the usual recurrences `h_i = a*h_{i-1} + h_{i-2}` and
+/// `k_i = a*k_{i-1} + k_{i-2}`. The expansion runs for at
+/// most `max_terms` terms, returning the last convergent; `max_terms == 0`
+/// is treated like one term and returns the floor `a_0 = floor(sqrt(r))`.
+///
+/// # Arguments
+///
+/// * `r` - A non-negative rational whose square root is approximated
+/// * `max_terms` - The maximum number of continued-fraction terms to use
+///
+/// # Returns
+///
+/// The best rational convergent `h/k` to `sqrt(r)`. A perfect-square `r`
+/// yields the exact value, and `r == 0` yields `0`.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::approx_sqrt;
+/// use num_rational::Ratio;
+///
+/// // sqrt(4) is exact in one step.
+/// assert_eq!(approx_sqrt(Ratio::new(4, 1), 10), Ratio::new(2, 1));
+/// // A convergent to sqrt(2).
+/// assert_eq!(approx_sqrt(Ratio::new(2, 1), 4), Ratio::new(17, 12));
+/// ```
+pub fn approx_sqrt(r: Ratio<i64>, max_terms: usize) -> Ratio<i64> {
+    use num_traits::Zero;
+    debug_assert!(
+        *r.numer() >= 0,
+        "approx_sqrt requires a non-negative rational"
+    );
+    if r.is_zero() {
+        return Ratio::zero();
+    }
+    let numer = *r.numer() as i128;
+    let denom = *r.denom() as i128;
+    let d = numer * denom;
+    let s = isqrt_i128(d);
+    // Perfect square: sqrt(r) = s / denom is exact.
+    if s * s == d {
+        return Ratio::new(s as i64, denom as i64);
+    }
+    // With no terms requested, the floor convergent a_0 = floor(sqrt(r)) is the
+    // best we can offer; return it rather than the degenerate h_{-1}/k_{-1}.
+    if max_terms == 0 {
+        return Ratio::new((s / denom) as i64, 1);
+    }
+
+    let mut p: i128 = 0;
+    let mut q: i128 = denom;
+    // Convergent recurrences: seeds h_{-1}=1, h_{-2}=0, k_{-1}=0, k_{-2}=1.
+    let (mut h_prev, mut h_prev2) = (1i128, 0i128);
+    let (mut k_prev, mut k_prev2) = (0i128, 1i128);
+
+    for _ in 0..max_terms {
+        let a = (p + s) / q;
+        let h = a * h_prev + h_prev2;
+        let k = a * k_prev + k_prev2;
+        h_prev2 = h_prev;
+        h_prev = h;
+        k_prev2 = k_prev;
+        k_prev = k;
+
+        let p_next = a * q - p;
+        let q_next = (d - p_next * p_next) / q;
+        p = p_next;
+        q = q_next;
+        // A zero `Q'` means the surd has terminated exactly (perfect-square
+        // region); the convergent is then the exact value and the periodic
+        // expansion would only repeat, so stop early.
+        if q == 0 {
+            break;
+        }
+    }
+    Ratio::new(h_prev as i64, k_prev as i64)
+}
+
+/// The `approx_length` function returns a rational approximation of the
+/// length (distance) corresponding to a quadrance `q`, i.e. a convergent to
+/// `sqrt(q)`.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::approx_length;
+/// use num_rational::Ratio;
+///
+/// assert_eq!(approx_length(Ratio::new(25, 1)), Ratio::new(5, 1));
+/// ```
+pub fn approx_length(q: Ratio<i64>) -> Ratio<i64> {
+    approx_sqrt(q, 20)
+}
+
+/// The `approx_sine` function returns a rational approximation of the sine of
+/// an angle given its spread `s`, i.e. a convergent to `sqrt(s)`.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::approx_sine;
+/// use num_rational::Ratio;
+///
+/// assert_eq!(approx_sine(Ratio::new(0, 1)), Ratio::new(0, 1));
+/// ```
+pub fn approx_sine(s: Ratio<i64>) -> Ratio<i64> {
+    approx_sqrt(s, 20)
+}
+
+
+/// The `isqrt` function computes the integer square root of `n`, i.e. the
+/// largest `u64` whose square does not exceed `n`.
+///
+/// It uses Newton's iteration `x <- (x + n/x) / 2` starting from a
+/// power-of-two seed above `sqrt(n)`, which converges monotonically down to
+/// `floor(sqrt(n))`.
+///
+/// # Arguments
+///
+/// * `n` - The value whose integer square root is computed
+///
+/// # Returns
+///
+/// The floor of the square root of `n`.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::isqrt;
+///
+/// assert_eq!(isqrt(0), 0);
+/// assert_eq!(isqrt(15), 3);
+/// assert_eq!(isqrt(16), 4);
+/// ```
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Power-of-two seed that is guaranteed to be at least sqrt(n).
+    let mut x = 1u64 << (64 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// The `rational_length` function returns the exact rational distance
+/// corresponding to a quadrance `q`, when such a rational distance exists.
+///
+/// The square root of a reduced fraction `p/q` is rational exactly when both
+/// `p` and `q` are perfect squares, in which case the distance is
+/// `isqrt(p) / isqrt(q)`. Otherwise there is no rational length and `None` is
+/// returned. A negative quadrance has no real distance and also yields `None`.
+///
+/// # Arguments
+///
+/// * `q` - The quadrance (squared distance)
+///
+/// # Returns
+///
+/// `Some(length)` when the distance is rational, `None` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use rat_trig::rational_length;
+/// use num_rational::Ratio;
+///
+/// assert_eq!(rational_length(Ratio::new(25, 1)), Some(Ratio::new(5, 1)));
+/// assert_eq!(rational_length(Ratio::new(9, 4)), Some(Ratio::new(3, 2)));
+/// assert_eq!(rational_length(Ratio::new(2, 1)), None);
+/// ```
+pub fn rational_length(q: Ratio<i64>) -> Option<Ratio<i64>> {
+    let numer = *q.numer();
+    let denom = *q.denom();
+    if numer < 0 {
+        return None;
+    }
+    let p = numer as u64;
+    let d = denom as u64;
+    let rp = isqrt(p);
+    let rd = isqrt(d);
+    if rp * rp == p && rd * rd == d {
+        Some(Ratio::new(rp as i64, rd as i64))
+    } else {
+        None
+    }
+}
+
+/// Quadrance-related predicates built on the integer square root.
+pub mod quad {
+    use num_rational::Ratio;
+
+    /// The `is_square` function reports whether a quadrance `q` corresponds to
+    /// an exactly rational distance, i.e. whether `sqrt(q)` is rational.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rat_trig::quad::is_square;
+    /// use num_rational::Ratio;
+    ///
+    /// assert!(is_square(Ratio::new(25, 1)));
+    /// assert!(!is_square(Ratio::new(2, 1)));
+    /// ```
+    pub fn is_square(q: Ratio<i64>) -> bool {
+        crate::rational_length(q).is_some()
+    }
 }
 
 #[cfg(test)]
@@ -505,6 +1080,237 @@ mod tests {
         let s_3_case3 = 0;
         assert_eq!(triple_quad_formula(q_1_case3, q_2_case3, s_3_case3), 0);
     }
+
+    #[test]
+    fn test_approx_sqrt() {
+        // Perfect-square rational: exact in one step
+        assert_eq!(approx_sqrt(Ratio::new(4, 1), 10), Ratio::new(2, 1));
+        assert_eq!(approx_sqrt(Ratio::new(9, 4), 10), Ratio::new(3, 2));
+
+        // Zero returns zero
+        assert_eq!(approx_sqrt(Ratio::new(0, 1), 10), Ratio::new(0, 1));
+
+        // Convergents to sqrt(2): [1; 2, 2, 2, ...]
+        assert_eq!(approx_sqrt(Ratio::new(2, 1), 1), Ratio::new(1, 1));
+        assert_eq!(approx_sqrt(Ratio::new(2, 1), 2), Ratio::new(3, 2));
+        assert_eq!(approx_sqrt(Ratio::new(2, 1), 3), Ratio::new(7, 5));
+        assert_eq!(approx_sqrt(Ratio::new(2, 1), 4), Ratio::new(17, 12));
+
+        // A convergent to sqrt(7)
+        assert_eq!(approx_sqrt(Ratio::new(7, 1), 4), Ratio::new(8, 3));
+
+        // Zero terms yields the floor convergent instead of panicking
+        assert_eq!(approx_sqrt(Ratio::new(2, 1), 0), Ratio::new(1, 1));
+        assert_eq!(approx_sqrt(Ratio::new(7, 1), 0), Ratio::new(2, 1));
+    }
+
+    #[test]
+    fn test_approx_length() {
+        // Exact length for a perfect-square quadrance
+        assert_eq!(approx_length(Ratio::new(25, 1)), Ratio::new(5, 1));
+        // Rational quadrance with rational length
+        assert_eq!(approx_length(Ratio::new(1, 4)), Ratio::new(1, 2));
+    }
+
+    #[test]
+    fn test_approx_sine() {
+        // Spread of a right angle is 1, so the sine is 1
+        assert_eq!(approx_sine(Ratio::new(1, 1)), Ratio::new(1, 1));
+        // Zero spread gives zero sine
+        assert_eq!(approx_sine(Ratio::new(0, 1)), Ratio::new(0, 1));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(17), 4);
+        assert_eq!(isqrt(1 << 52), 1 << 26);
+        assert_eq!(isqrt(u64::MAX), 4294967295);
+    }
+
+    #[test]
+    fn test_rational_length() {
+        // Perfect-square numerator and denominator
+        assert_eq!(rational_length(Ratio::new(25, 1)), Some(Ratio::new(5, 1)));
+        assert_eq!(rational_length(Ratio::new(9, 4)), Some(Ratio::new(3, 2)));
+        assert_eq!(rational_length(Ratio::new(0, 1)), Some(Ratio::new(0, 1)));
+
+        // Irrational lengths
+        assert_eq!(rational_length(Ratio::new(2, 1)), None);
+        assert_eq!(rational_length(Ratio::new(1, 2)), None);
+
+        // Negative quadrance has no real length
+        assert_eq!(rational_length(Ratio::new(-4, 1)), None);
+    }
+
+    #[test]
+    fn test_quad_is_square() {
+        assert!(quad::is_square(Ratio::new(25, 1)));
+        assert!(quad::is_square(Ratio::new(9, 4)));
+        assert!(!quad::is_square(Ratio::new(2, 1)));
+        assert!(!quad::is_square(Ratio::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_bigrational() {
+        use num_bigint::BigInt;
+        use num_rational::BigRational;
+
+        let big = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+
+        // The same result as the `i64` Ratio case, but with no overflow risk.
+        assert_eq!(
+            archimedes(big(1, 2), big(1, 4), big(1, 6)),
+            big(23, 144)
+        );
+
+        // Products that square the magnitude stay exact for arbitrary size.
+        let q_1 = BigRational::from(BigInt::from(1_000_000_000i64));
+        let q_2 = q_1.clone();
+        let s_3 = big(0, 1);
+        assert_eq!(
+            triple_quad_formula(q_1, q_2, s_3),
+            BigRational::from(BigInt::from(0i64))
+        );
+    }
+
+    #[test]
+    fn test_checked_archimedes() {
+        // Matches the infallible version for in-range inputs
+        assert_eq!(checked_archimedes(2, 4, 6), Some(32));
+        assert_eq!(checked_archimedes(2, 4, 6), Some(archimedes(2, 4, 6)));
+
+        // Overflow detected instead of wrapping
+        assert_eq!(checked_archimedes(i32::MAX, i32::MAX, 0), None);
+        assert_eq!(checked_archimedes(i64::MAX, i64::MAX, 0), None);
+    }
+
+    #[test]
+    fn test_checked_quad() {
+        assert_eq!(checked_quad([3, 4]), Some(25));
+        assert_eq!(checked_quad([3, 4]), Some(quad([3, 4])));
+        assert_eq!(checked_quad([i32::MAX, 0]), None);
+    }
+
+    #[test]
+    fn test_checked_triple_quad_formula() {
+        assert_eq!(checked_triple_quad_formula(1, 1, 1), Some(4));
+        assert_eq!(
+            checked_triple_quad_formula(1, 1, 0),
+            Some(triple_quad_formula(1, 1, 0))
+        );
+        assert_eq!(checked_triple_quad_formula(i64::MAX, i64::MAX, 0), None);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let t = Triangle::from_quadrances(
+            Ratio::new(5, 1),
+            Ratio::new(25, 1),
+            Ratio::new(20, 1),
+        );
+        assert_eq!(
+            t.solve_spreads(),
+            [Ratio::new(1, 5), Ratio::new(1, 1), Ratio::new(4, 5)]
+        );
+        assert!(t.is_consistent());
+
+        // An equilateral triangle has three equal spreads of 3/4.
+        let eq = Triangle::from_quadrances(Ratio::new(1, 1), Ratio::new(1, 1), Ratio::new(1, 1));
+        assert_eq!(
+            eq.spreads,
+            [Ratio::new(3, 4), Ratio::new(3, 4), Ratio::new(3, 4)]
+        );
+        assert!(eq.is_consistent());
+    }
+
+    #[test]
+    fn test_cross_law() {
+        // Solve-for-spread direction, opposite two different quadrances
+        assert_eq!(
+            cross_law(Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1)),
+            Ratio::new(4, 5)
+        );
+        assert_eq!(
+            cross_law(Ratio::new(25, 1), Ratio::new(20, 1), Ratio::new(5, 1)),
+            Ratio::new(1, 5)
+        );
+        // Matches the spread-law value it generalizes
+        assert_eq!(
+            cross_law(5.0, 25.0, 20.0),
+            spread_law(5.0, 25.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_triangle_from_quadrances_and_spread() {
+        // Solving the cross law for the missing quadrance recovers (5, 25, 20)
+        let t = Triangle::from_quadrances_and_spread(
+            Ratio::new(5, 1),
+            Ratio::new(25, 1),
+            Ratio::new(4, 5),
+        )
+        .unwrap();
+        assert_eq!(
+            t.quadrances,
+            [Ratio::new(5, 1), Ratio::new(25, 1), Ratio::new(20, 1)]
+        );
+        assert_eq!(
+            t.spreads,
+            [Ratio::new(1, 5), Ratio::new(1, 1), Ratio::new(4, 5)]
+        );
+        assert!(t.is_consistent());
+
+        // An irrational third quadrance leaves exact arithmetic: None
+        assert_eq!(
+            Triangle::from_quadrances_and_spread(
+                Ratio::new(1, 1),
+                Ratio::new(1, 1),
+                Ratio::new(1, 2),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dot_m() {
+        assert_eq!(dot_m([1, 2], [3, 4], Metric::Blue), 11);
+        assert_eq!(dot_m([1, 2], [3, 4], Metric::Blue), dot([1, 2], [3, 4]));
+        assert_eq!(dot_m([1, 2], [3, 4], Metric::Red), -5);
+        assert_eq!(dot_m([1, 2], [3, 4], Metric::Green), 10);
+    }
+
+    #[test]
+    fn test_quad_m() {
+        assert_eq!(quad_m([3, 4], Metric::Blue), 25);
+        assert_eq!(quad_m([3, 4], Metric::Blue), quad([3, 4]));
+        assert_eq!(quad_m([3, 4], Metric::Red), -7);
+        assert_eq!(quad_m([3, 4], Metric::Green), 24);
+    }
+
+    #[test]
+    fn test_spread_m() {
+        let v_1 = [Ratio::new(1, 1), Ratio::new(2, 1)];
+        let v_2 = [Ratio::new(3, 1), Ratio::new(4, 1)];
+
+        // Blue reproduces the Euclidean spread
+        assert_eq!(spread_m(v_1, v_2, Metric::Blue), Some(Ratio::new(4, 125)));
+        assert_eq!(spread_m(v_1, v_2, Metric::Blue), Some(spread(v_1, v_2)));
+
+        // Spreads stay rational in the relativistic geometries
+        let r_1 = [Ratio::new(2, 1), Ratio::new(1, 1)];
+        let r_2 = [Ratio::new(1, 1), Ratio::new(0, 1)];
+        assert_eq!(spread_m(r_1, r_2, Metric::Red), Some(Ratio::new(-1, 3)));
+        assert_eq!(spread_m(v_1, v_2, Metric::Green), Some(Ratio::new(-1, 24)));
+
+        // Null vector under the red metric gives no spread
+        let null = [Ratio::new(1, 1), Ratio::new(1, 1)];
+        assert_eq!(spread_m(null, v_2, Metric::Red), None);
+        assert_eq!(spread_m(v_2, null, Metric::Red), None);
+    }
 }
 
 /// Fibonacci example function